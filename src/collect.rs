@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use proc_macro2::LineColumn;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Expr, ExprCall, ExprMethodCall, Item, ItemFn, Lit, Stmt, UseTree};
+
+use crate::types::CallSite;
+
+/// Parses `file` and collects every call site in it.
+pub fn collect_file(file: &Path) -> Result<Vec<CallSite>> {
+    let src = fs::read_to_string(file).with_context(|| format!("reading {}", file.display()))?;
+    collect_str(file.to_path_buf(), &src)
+}
+
+/// Parses `src` as if it were the contents of `file` and collects every
+/// call site in it. Split out from [`collect_file`] so detectors' unit
+/// tests can exercise the collector against inline snippets instead of
+/// real files on disk.
+pub fn collect_str(file: PathBuf, src: &str) -> Result<Vec<CallSite>> {
+    let parsed = syn::parse_file(src).with_context(|| format!("parsing {}", file.display()))?;
+    let aliases = collect_use_aliases(&parsed.items);
+
+    let mut collector = CallCollector {
+        file,
+        current_stmt: 0,
+        current_fn: None,
+        current_fn_traced: false,
+        current_let_binding: None,
+        aliases,
+        sites: Vec::new(),
+    };
+    collector.visit_file(&parsed);
+    Ok(collector.sites)
+}
+
+struct CallCollector {
+    file: PathBuf,
+    current_stmt: usize,
+    current_fn: Option<String>,
+    current_fn_traced: bool,
+    /// Local name -> fully qualified path, from this file's top-level `use`
+    /// statements, so a call written through an imported name (e.g. `use
+    /// async_openai::Client; Client::new()`) resolves to the same `callee`
+    /// as the fully qualified spelling.
+    aliases: HashMap<String, String>,
+    /// The `let <name> = <init>;` binding currently being visited, if any:
+    /// the bound name plus the span of the real call the initializer
+    /// resolves to once `.unwrap()`/`.expect()`/`?`/`.await` wrappers are
+    /// peeled off (see [`unwrap_call_target`]). Matched by span rather than
+    /// "first call site visited" so the binding lands on the actual
+    /// producing call (e.g. `get_connection()`) and not on whichever wrapper
+    /// syn's pre-order traversal happens to visit first (e.g. `.unwrap()`).
+    current_let_binding: Option<(String, LineColumn, LineColumn)>,
+    sites: Vec<CallSite>,
+}
+
+/// Flattens a file's top-level `use` statements into `local name -> fully
+/// qualified path` entries. `use`s inside functions or nested modules
+/// aren't resolved.
+fn collect_use_aliases(items: &[Item]) -> HashMap<String, String> {
+    let mut aliases = HashMap::new();
+    for item in items {
+        if let Item::Use(item_use) = item {
+            flatten_use_tree(&item_use.tree, String::new(), &mut aliases);
+        }
+    }
+    aliases
+}
+
+fn flatten_use_tree(tree: &UseTree, prefix: String, aliases: &mut HashMap<String, String>) {
+    let joined = |ident: &syn::Ident| {
+        if prefix.is_empty() {
+            ident.to_string()
+        } else {
+            format!("{prefix}::{ident}")
+        }
+    };
+    match tree {
+        UseTree::Path(p) => flatten_use_tree(&p.tree, joined(&p.ident), aliases),
+        UseTree::Name(n) => {
+            if n.ident != "self" {
+                aliases.insert(n.ident.to_string(), joined(&n.ident));
+            }
+        }
+        UseTree::Rename(r) => {
+            aliases.insert(r.rename.to_string(), joined(&r.ident));
+        }
+        UseTree::Group(g) => {
+            for t in &g.items {
+                flatten_use_tree(t, prefix.clone(), aliases);
+            }
+        }
+        UseTree::Glob(_) => {}
+    }
+}
+
+/// True if any attribute is `#[instrument]` / `#[tracing::instrument]`.
+fn has_instrument_attr(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().segments.last().is_some_and(|s| s.ident == "instrument"))
+}
+
+fn expr_repr(expr: &Expr) -> String {
+    quote!(#expr).to_string()
+}
+
+fn lit_str(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The name a `let` pattern binds to, when it's a plain (possibly `mut` or
+/// type-ascribed) identifier rather than a destructuring pattern.
+fn local_ident(pat: &syn::Pat) -> Option<String> {
+    match pat {
+        syn::Pat::Ident(p) => Some(p.ident.to_string()),
+        syn::Pat::Type(t) => local_ident(&t.pat),
+        _ => None,
+    }
+}
+
+/// Peels an initializer expression down to the call whose result is really
+/// being bound: through `?`, `.await`, and trailing `.unwrap()`/`.expect(..)`
+/// calls, so `client.get_connection().unwrap()` resolves to the
+/// `get_connection()` call rather than the `.unwrap()` wrapping it.
+fn unwrap_call_target(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Try(e) => unwrap_call_target(&e.expr),
+        Expr::Await(e) => unwrap_call_target(&e.base),
+        Expr::MethodCall(m) if matches!(m.method.to_string().as_str(), "unwrap" | "expect") => {
+            unwrap_call_target(&m.receiver)
+        }
+        _ => expr,
+    }
+}
+
+/// Joins a plain path expression (`a::b::c`) into a `"a::b::c"` string, the
+/// way it's used for free/associated-function calls like
+/// `redis::Client::open(..)`. The leading segment is resolved against
+/// `aliases` first, so `Client::new()` under `use async_openai::Client;`
+/// joins to the same string as the fully qualified
+/// `async_openai::Client::new()` spelling.
+fn path_repr(expr: &Expr, aliases: &HashMap<String, String>) -> Option<String> {
+    match expr {
+        Expr::Path(p) => {
+            let mut segments = p.path.segments.iter().map(|s| s.ident.to_string());
+            let first = segments.next()?;
+            let first = aliases.get(&first).cloned().unwrap_or(first);
+            Some(std::iter::once(first).chain(segments).collect::<Vec<_>>().join("::"))
+        }
+        _ => None,
+    }
+}
+
+impl CallCollector {
+    /// Returns the pending `let` binding's name when `span` is exactly the
+    /// target span [`visit_local`] recorded for it -- i.e. `span` is the
+    /// call that binding's initializer actually resolves to, not some other
+    /// call nested inside the same initializer (e.g. an argument).
+    fn let_binding_for(&self, span: proc_macro2::Span) -> Option<String> {
+        let (name, start, end) = self.current_let_binding.as_ref()?;
+        (*start == span.start() && *end == span.end()).then(|| name.clone())
+    }
+}
+
+impl<'ast> Visit<'ast> for CallCollector {
+    fn visit_stmt(&mut self, node: &'ast Stmt) {
+        self.current_stmt += 1;
+        visit::visit_stmt(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'ast syn::Local) {
+        let target = local_ident(&node.pat).zip(node.init.as_ref()).map(|(name, init)| {
+            let target_expr = unwrap_call_target(&init.expr);
+            (name, target_expr.span().start(), target_expr.span().end())
+        });
+        let prev = self.current_let_binding.take();
+        self.current_let_binding = target;
+        visit::visit_local(self, node);
+        self.current_let_binding = prev;
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        let prev_fn = self.current_fn.replace(node.sig.ident.to_string());
+        let prev_traced = self.current_fn_traced;
+        self.current_fn_traced = has_instrument_attr(&node.attrs);
+        visit::visit_item_fn(self, node);
+        self.current_fn = prev_fn;
+        self.current_fn_traced = prev_traced;
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let Some(callee) = path_repr(&node.func, &self.aliases) {
+            self.sites.push(CallSite {
+                file: self.file.clone(),
+                line: node.span().start().line,
+                stmt_index: self.current_stmt,
+                callee,
+                receiver_repr: None,
+                arg_reprs: node.args.iter().map(expr_repr).collect(),
+                arg_lits: node.args.iter().map(lit_str).collect(),
+                enclosing_fn: self.current_fn.clone(),
+                in_traced_fn: self.current_fn_traced,
+                let_binding: self.let_binding_for(node.span()),
+            });
+        }
+        visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let receiver = &node.receiver;
+        self.sites.push(CallSite {
+            file: self.file.clone(),
+            // `ExprMethodCall`'s own span starts at the receiver, not the
+            // method -- for a multi-line chain that reports every call in
+            // the chain on the chain's first line. `node.method`'s span is
+            // the method name itself, which is the actual per-call line.
+            line: node.method.span().start().line,
+            stmt_index: self.current_stmt,
+            callee: node.method.to_string(),
+            receiver_repr: Some(quote!(#receiver).to_string()),
+            arg_reprs: node.args.iter().map(expr_repr).collect(),
+            arg_lits: node.args.iter().map(lit_str).collect(),
+            enclosing_fn: self.current_fn.clone(),
+            in_traced_fn: self.current_fn_traced,
+            let_binding: self.let_binding_for(node.span()),
+        });
+        visit::visit_expr_method_call(self, node);
+    }
+}
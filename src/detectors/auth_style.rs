@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+
+use super::Detector;
+use crate::types::{CallSite, Finding, Severity};
+
+/// Classifies how each detected outbound call authenticates: AWS SigV4
+/// (implied by the `aws_config`/`aws_sdk_*` path), HTTP-signature signing (a
+/// request builder with a sibling `Signature`/`Digest` header), or unsigned
+/// bearer/basic auth — and separately flags any webhook POST sent with
+/// neither a signature nor a digest attached.
+pub struct AuthStyleDetector;
+
+impl Detector for AuthStyleDetector {
+    fn name(&self) -> &'static str {
+        "auth_style"
+    }
+
+    fn scan(&self, sites: &[CallSite]) -> Vec<Finding> {
+        let signed_chains = signature_header_chains(sites);
+        let post_chains = post_method_chains(sites);
+        let mut findings = Vec::new();
+
+        for site in sites {
+            if is_aws_sdk_call(site) {
+                findings.push(self.finding(
+                    site,
+                    Severity::Info,
+                    "aws",
+                    "AWS SDK call authenticated via SigV4 request signing".to_string(),
+                ));
+            } else if let Some(service) = implicit_bearer_sdk(site) {
+                findings.push(self.finding(
+                    site,
+                    Severity::Info,
+                    service,
+                    format!("{service} call authenticated with an unsigned bearer token"),
+                ));
+            } else if is_webhook_uri(site)
+                && post_chains.contains(&(site.file.clone(), site.stmt_index))
+            {
+                if signed_chains.contains(&(site.file.clone(), site.stmt_index)) {
+                    findings.push(self.finding(
+                        site,
+                        Severity::Info,
+                        "webhook",
+                        "outbound webhook POST is signed via a Signature/Digest header".to_string(),
+                    ));
+                } else {
+                    findings.push(self.finding(
+                        site,
+                        Severity::Medium,
+                        "webhook",
+                        "outbound webhook POST has no Signature/Digest header attached".to_string(),
+                    ));
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+impl AuthStyleDetector {
+    fn finding(
+        &self,
+        site: &CallSite,
+        severity: Severity,
+        service: &'static str,
+        message: String,
+    ) -> Finding {
+        Finding {
+            detector: self.name(),
+            severity,
+            file: site.file.clone(),
+            line: site.line,
+            service,
+            message,
+        }
+    }
+}
+
+fn is_aws_sdk_call(site: &CallSite) -> bool {
+    site.receiver_repr.is_none()
+        && (site.callee.starts_with("aws_config::") || site.callee.starts_with("aws_sdk_"))
+}
+
+/// SDKs that attach a bearer/API key at client construction rather than via
+/// a visible `.header(...)` call.
+fn implicit_bearer_sdk(site: &CallSite) -> Option<&'static str> {
+    if site.receiver_repr.is_some() {
+        return None;
+    }
+    if super::ends_with_path(&site.callee, "Charge::create") || site.callee == "stripe::Client::new"
+    {
+        Some("stripe")
+    } else if site.callee == "async_openai::Client::new" {
+        Some("openai")
+    } else {
+        None
+    }
+}
+
+/// The `uri(...)` step of a `Request::builder()` chain whose literal
+/// argument looks like a webhook endpoint.
+fn is_webhook_uri(site: &CallSite) -> bool {
+    site.is_method("uri") && site.arg_lit(0).is_some_and(|uri| uri.contains("webhook"))
+}
+
+/// The `(file, stmt_index)` groups that carry a `.method(Method::POST)` call,
+/// i.e. chains building a POST request.
+fn post_method_chains(sites: &[CallSite]) -> HashSet<(std::path::PathBuf, usize)> {
+    sites
+        .iter()
+        .filter(|site| site.is_method("method") && site.arg_repr(0) == Some("Method :: POST"))
+        .map(|site| (site.file.clone(), site.stmt_index))
+        .collect()
+}
+
+/// The `(file, stmt_index)` groups that carry a `.header("Signature", ..)`
+/// or `.header("Digest", ..)` call, i.e. chains that sign their request.
+fn signature_header_chains(sites: &[CallSite]) -> HashSet<(std::path::PathBuf, usize)> {
+    sites
+        .iter()
+        .filter(|site| {
+            site.is_method("header")
+                && matches!(site.arg_lit(0), Some("Signature") | Some("Digest"))
+        })
+        .map(|site| (site.file.clone(), site.stmt_index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::collect::collect_str;
+
+    fn scan(src: &str) -> Vec<Finding> {
+        let sites = collect_str(PathBuf::from("probe.rs"), src).unwrap();
+        AuthStyleDetector.scan(&sites)
+    }
+
+    #[test]
+    fn recognizes_fully_qualified_openai_client() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let openai = async_openai::Client::new();
+            }
+            "#,
+        );
+        assert!(findings.iter().any(|f| f.service == "openai"));
+    }
+
+    #[test]
+    fn recognizes_openai_client_via_use_alias() {
+        let findings = scan(
+            r#"
+            use async_openai::Client;
+            fn f() {
+                let openai = Client::new();
+            }
+            "#,
+        );
+        assert!(
+            findings.iter().any(|f| f.service == "openai"),
+            "expected an openai finding for Client::new() imported via `use async_openai::Client;`, got {findings:?}"
+        );
+    }
+
+    #[test]
+    fn flags_unsigned_webhook_post() {
+        let findings = scan(
+            r#"
+            async fn f() {
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("https://example.com/webhook")
+                    .body(payload)?;
+            }
+            "#,
+        );
+        assert!(findings
+            .iter()
+            .any(|f| f.service == "webhook" && f.message.contains("no Signature")));
+    }
+
+    #[test]
+    fn recognizes_signed_webhook_post() {
+        let findings = scan(
+            r#"
+            async fn f() {
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("https://example.com/webhook")
+                    .header("Signature", sig)
+                    .body(payload)?;
+            }
+            "#,
+        );
+        assert!(findings
+            .iter()
+            .any(|f| f.service == "webhook" && f.message.contains("is signed")));
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use super::{ends_with_path, Detector};
+use crate::types::{CallSite, Finding, Severity};
+
+/// Audits how each HTTP egress point is configured rather than just noting
+/// that it exists, so an egress-policy review can see which integrations
+/// route through a proxy or a custom DNS resolver versus connecting
+/// directly. Groups reqwest, ureq, and hyper call sites into one surface.
+///
+/// TLS backend selection is only detected when chosen explicitly in code
+/// (`.use_rustls_tls()` / `.use_native_tls()`); a backend forced purely via
+/// Cargo feature flags on the `reqwest` dependency isn't visible to this
+/// detector, since it only scans `.rs` sources.
+pub struct HttpClientConfigDetector;
+
+impl Detector for HttpClientConfigDetector {
+    fn name(&self) -> &'static str {
+        "http_client_config"
+    }
+
+    fn scan(&self, sites: &[CallSite]) -> Vec<Finding> {
+        let chains = group_by_chain(sites);
+        let mut findings = Vec::new();
+        let mut audited_chains: HashSet<(PathBuf, usize)> = HashSet::new();
+
+        for site in sites {
+            if site.receiver_repr.is_some() {
+                continue;
+            }
+            let chain = (site.file.clone(), site.stmt_index);
+
+            // Matched by exact path rather than `ends_with_path`: `Client::new`/
+            // `Client::builder` are generic enough that a suffix match would
+            // also catch `stripe::Client::new` or `async_openai::Client::new`.
+            if site.callee == "reqwest::Client::builder" && audited_chains.insert(chain.clone()) {
+                findings.push(self.builder_finding(site, &chains[&chain]));
+            } else if site.callee == "reqwest::Client::new" || site.callee == "reqwest::get" {
+                findings.push(self.finding(
+                    site,
+                    "reqwest",
+                    "default config (no proxy, custom DNS resolver, pool limits, or TLS override)"
+                        .to_string(),
+                ));
+            } else if site.callee == "ureq::get" || site.callee == "ureq::post" {
+                findings.push(self.finding(site, "ureq", "default config".to_string()));
+            } else if ends_with_path(&site.callee, "Request::builder")
+                && audited_chains.insert(chain)
+            {
+                findings.push(self.finding(
+                    site,
+                    "hyper",
+                    "request builder has no client-level egress config (proxy/DNS/pool are set \
+                     on the hyper Client, not the request builder)"
+                        .to_string(),
+                ));
+            }
+        }
+
+        findings
+    }
+}
+
+impl HttpClientConfigDetector {
+    fn finding(&self, site: &CallSite, service: &'static str, message: String) -> Finding {
+        Finding {
+            detector: self.name(),
+            severity: Severity::Info,
+            file: site.file.clone(),
+            line: site.line,
+            service,
+            message,
+        }
+    }
+
+    /// Inspects every call site in the same builder chain as `site` for
+    /// proxy, DNS resolver, connection pool, and TLS backend configuration.
+    fn builder_finding(&self, site: &CallSite, chain: &[&CallSite]) -> Finding {
+        let mut configured = Vec::new();
+        if chain.iter().any(|s| s.is_method("proxy")) {
+            configured.push("proxy");
+        }
+        if chain
+            .iter()
+            .any(|s| s.is_method("dns_resolver") || s.is_method("resolve"))
+        {
+            configured.push("custom DNS resolver");
+        }
+        if chain
+            .iter()
+            .any(|s| s.is_method("pool_max_idle_per_host") || s.is_method("pool_idle_timeout"))
+        {
+            configured.push("connection pool limits");
+        }
+        if chain.iter().any(|s| s.is_method("use_rustls_tls")) {
+            configured.push("rustls TLS backend");
+        } else if chain.iter().any(|s| s.is_method("use_native_tls")) {
+            configured.push("native-tls backend");
+        }
+
+        let message = if configured.is_empty() {
+            "client builder uses default config (no proxy, DNS resolver, pool limits, or TLS \
+             override set)"
+                .to_string()
+        } else {
+            format!("client builder configured with {}", configured.join(", "))
+        };
+        self.finding(site, "reqwest", message)
+    }
+}
+
+fn group_by_chain(sites: &[CallSite]) -> HashMap<(PathBuf, usize), Vec<&CallSite>> {
+    let mut chains: HashMap<(PathBuf, usize), Vec<&CallSite>> = HashMap::new();
+    for site in sites {
+        chains
+            .entry((site.file.clone(), site.stmt_index))
+            .or_default()
+            .push(site);
+    }
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::collect::collect_str;
+
+    fn scan(src: &str) -> Vec<Finding> {
+        let sites = collect_str(PathBuf::from("probe.rs"), src).unwrap();
+        HttpClientConfigDetector.scan(&sites)
+    }
+
+    #[test]
+    fn flags_default_config_on_bare_client_new() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let client = reqwest::Client::new();
+            }
+            "#,
+        );
+        assert!(findings.iter().any(|f| f.message.contains("default config")));
+    }
+
+    #[test]
+    fn reports_configured_builder_options() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let client = reqwest::Client::builder()
+                    .proxy(proxy)
+                    .pool_max_idle_per_host(4)
+                    .use_rustls_tls()
+                    .build()?;
+            }
+            "#,
+        );
+        assert!(findings.iter().any(|f| f.message.contains("proxy")
+            && f.message.contains("connection pool limits")
+            && f.message.contains("rustls")));
+    }
+
+    #[test]
+    fn flags_default_builder_with_no_options_set() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let client = reqwest::Client::builder().build()?;
+            }
+            "#,
+        );
+        assert!(findings.iter().any(|f| f.message.contains("default config")));
+    }
+
+    #[test]
+    fn does_not_confuse_stripe_or_openai_constructors_with_reqwest() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let stripe_client = stripe::Client::new(stripe_key);
+                let openai = async_openai::Client::new();
+            }
+            "#,
+        );
+        assert!(
+            findings.is_empty(),
+            "expected no reqwest findings for non-reqwest Client::new() calls, got {findings:?}"
+        );
+    }
+
+    #[test]
+    fn flags_hyper_request_builder_once_per_chain() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let req = Request::builder().method(Method::POST).uri(url).body(payload)?;
+            }
+            "#,
+        );
+        assert_eq!(findings.iter().filter(|f| f.service == "hyper").count(), 1);
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use super::Detector;
+use crate::types::{CallSite, Finding, Severity};
+
+/// Flags external call sites missing the instrumentation a production HTTP
+/// client is expected to carry: an explicit timeout, a tracing span, and a
+/// shared (not freshly-constructed-per-call) client.
+///
+/// This works purely off the call site's own statement and enclosing
+/// function, so a shared client whose timeout is configured once at
+/// construction (elsewhere in the file) will still be reported as missing a
+/// timeout at each call site that uses it — callers should treat "no
+/// timeout" findings on calls through a named, reused client as lower
+/// confidence than ones on an inline/one-shot client.
+pub struct InstrumentationGapDetector;
+
+impl Detector for InstrumentationGapDetector {
+    fn name(&self) -> &'static str {
+        "instrumentation"
+    }
+
+    fn scan(&self, sites: &[CallSite]) -> Vec<Finding> {
+        let timed_chains = chains_where(sites, |s| s.callee == "timeout");
+        let spanned_chains = chains_where(sites, |s| s.callee == "instrument");
+
+        let mut findings = Vec::new();
+        for site in sites {
+            let Some(service) = external_call_service(site) else {
+                continue;
+            };
+            let chain = (site.file.clone(), site.stmt_index);
+
+            if !timed_chains.contains(&chain) {
+                findings.push(self.finding(
+                    site,
+                    Severity::Medium,
+                    service,
+                    "unbounded outbound call -- no timeout".to_string(),
+                ));
+            }
+            if !site.in_traced_fn && !spanned_chains.contains(&chain) {
+                findings.push(self.finding(
+                    site,
+                    Severity::Low,
+                    service,
+                    "outbound call has no enclosing tracing span".to_string(),
+                ));
+            }
+        }
+
+        for site in sites {
+            if is_inline_reqwest_client(site) {
+                let function = site.enclosing_fn.as_deref().unwrap_or("<unknown>");
+                findings.push(self.finding(
+                    site,
+                    Severity::Medium,
+                    "reqwest",
+                    format!("client rebuilt per request in `{function}` -- consider a shared client"),
+                ));
+            }
+        }
+
+        findings
+    }
+}
+
+impl InstrumentationGapDetector {
+    fn finding(
+        &self,
+        site: &CallSite,
+        severity: Severity,
+        service: &'static str,
+        message: String,
+    ) -> Finding {
+        Finding {
+            detector: self.name(),
+            severity,
+            file: site.file.clone(),
+            line: site.line,
+            service,
+            message,
+        }
+    }
+}
+
+/// One of the call sites thirdwatch treats as an outbound network call:
+/// `reqwest::get`, `client.post(..).send()`, `ureq::get(..).call()`,
+/// `stripe::Charge::create`, and S3 object operations.
+fn external_call_service(site: &CallSite) -> Option<&'static str> {
+    if site.receiver_repr.is_none() {
+        if site.callee == "reqwest::get" {
+            return Some("reqwest");
+        }
+        if super::ends_with_path(&site.callee, "Charge::create") {
+            return Some("stripe");
+        }
+        return None;
+    }
+
+    if site.is_method("send") && is_request_builder_chain(site) {
+        return Some("http");
+    }
+    if site.is_method("call") && site.receiver_repr.as_deref().is_some_and(|r| r.contains("ureq")) {
+        return Some("ureq");
+    }
+    if is_s3_op(site) {
+        return Some("s3");
+    }
+    None
+}
+
+/// HTTP verb methods that start a reqwest/hyper request builder chain.
+const REQUEST_BUILDER_VERBS: &[&str] = &["get", "post", "put", "patch", "delete", "head"];
+
+/// True when `.send()`'s receiver chain shows it was actually built up from
+/// an HTTP request builder (`client.post(..).header(..)`), rather than an
+/// unrelated `.send(..)` such as `mpsc::Sender::send`. `receiver_repr` is
+/// the full rendered source of everything before `.send()`, so a verb call
+/// anywhere earlier in the chain shows up as `". post ("` etc.
+fn is_request_builder_chain(site: &CallSite) -> bool {
+    let Some(receiver) = site.receiver_repr.as_deref() else {
+        return false;
+    };
+    REQUEST_BUILDER_VERBS
+        .iter()
+        .any(|verb| receiver.contains(&format!(". {verb} (")))
+}
+
+fn is_s3_op(site: &CallSite) -> bool {
+    let is_s3_method = matches!(
+        site.callee.as_str(),
+        "put_object" | "get_object" | "list_objects_v2" | "delete_object"
+    );
+    is_s3_method
+        && site
+            .receiver_repr
+            .as_deref()
+            .is_some_and(|r| r.to_ascii_lowercase().contains("s3"))
+}
+
+/// A `reqwest::Client::new()` call made outside of `main`, the common case
+/// of rebuilding a client on every request instead of sharing one.
+fn is_inline_reqwest_client(site: &CallSite) -> bool {
+    site.receiver_repr.is_none()
+        && site.callee == "reqwest::Client::new"
+        && site.enclosing_fn.as_deref() != Some("main")
+}
+
+fn chains_where(
+    sites: &[CallSite],
+    pred: impl Fn(&CallSite) -> bool,
+) -> HashSet<(PathBuf, usize)> {
+    sites
+        .iter()
+        .filter(|s| pred(s))
+        .map(|s| (s.file.clone(), s.stmt_index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::collect::collect_str;
+
+    fn scan(src: &str) -> Vec<Finding> {
+        let sites = collect_str(PathBuf::from("probe.rs"), src).unwrap();
+        InstrumentationGapDetector.scan(&sites)
+    }
+
+    #[test]
+    fn flags_untimed_reqwest_send_chain() {
+        let findings = scan(
+            r#"
+            async fn f() {
+                client.post(url).header("X", "y").send().await?;
+            }
+            "#,
+        );
+        assert!(findings.iter().any(|f| f.service == "http" && f.message.contains("timeout")));
+    }
+
+    #[test]
+    fn does_not_flag_a_channel_send() {
+        let findings = scan(
+            r#"
+            fn f() {
+                tx.send(42).unwrap();
+            }
+            "#,
+        );
+        assert!(
+            findings.is_empty(),
+            "expected no findings for an mpsc-style .send(), got {findings:?}"
+        );
+    }
+
+    #[test]
+    fn does_not_flag_a_send_on_an_unrelated_chain() {
+        let findings = scan(
+            r#"
+            fn f() {
+                actor_handle.clone().send(message).await?;
+            }
+            "#,
+        );
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn recognizes_timeout_in_the_same_chain() {
+        let findings = scan(
+            r#"
+            async fn f() {
+                client.post(url).timeout(Duration::from_secs(5)).send().await?;
+            }
+            "#,
+        );
+        assert!(!findings.iter().any(|f| f.message.contains("no timeout")));
+    }
+}
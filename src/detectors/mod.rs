@@ -0,0 +1,33 @@
+pub mod auth_style;
+pub mod http_client_config;
+pub mod instrumentation;
+pub mod redis_roles;
+pub mod secrets;
+pub mod streaming;
+
+use crate::types::{CallSite, Finding};
+
+/// A single analysis pass over the call sites collected from a codebase.
+pub trait Detector {
+    fn name(&self) -> &'static str;
+    fn scan(&self, sites: &[CallSite]) -> Vec<Finding>;
+}
+
+/// All detectors thirdwatch runs, in report order.
+pub fn all() -> Vec<Box<dyn Detector>> {
+    vec![
+        Box::new(secrets::SecretDetector),
+        Box::new(auth_style::AuthStyleDetector),
+        Box::new(redis_roles::RedisRoleDetector),
+        Box::new(streaming::StreamingDetector),
+        Box::new(instrumentation::InstrumentationGapDetector),
+        Box::new(http_client_config::HttpClientConfigDetector),
+    ]
+}
+
+/// True if `callee` is exactly `suffix`, or a qualified path ending in
+/// `::{suffix}` (so `redis::Client::open` matches the suffix
+/// `Client::open` regardless of how the caller imported the type).
+pub(crate) fn ends_with_path(callee: &str, suffix: &str) -> bool {
+    callee == suffix || callee.ends_with(&format!("::{suffix}"))
+}
@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use super::{ends_with_path, Detector};
+use crate::types::{CallSite, Finding, Severity};
+
+/// Classifies each Redis command call site into the operational role it
+/// plays: cache, pub-sub, stream, or queue. Redis used as a message bus
+/// changes the dependency picture very differently than Redis used as a
+/// cache, so this is reported separately from the plain "Redis is present"
+/// fact the connection-string detector already surfaces.
+///
+/// Recognizes a command call either by following the handle back to a
+/// `redis::Client::open(..)` call through `let`-bindings (see
+/// [`redis_bound_idents`]), or -- when that chain isn't visible, e.g. the
+/// handle arrived as a function parameter -- by command name alone for
+/// commands distinctive enough that false positives are unlikely.
+pub struct RedisRoleDetector;
+
+impl Detector for RedisRoleDetector {
+    fn name(&self) -> &'static str {
+        "redis_roles"
+    }
+
+    fn scan(&self, sites: &[CallSite]) -> Vec<Finding> {
+        let redis_bound = redis_bound_idents(sites);
+
+        sites
+            .iter()
+            .filter_map(|site| {
+                let role = redis_role(site, &redis_bound)?;
+                let key = site.arg_lit(0);
+                let message = match key {
+                    Some(key) => format!("Redis used as a {role} (key `{key}`)"),
+                    None => format!("Redis used as a {role}"),
+                };
+                Some(Finding {
+                    detector: self.name(),
+                    severity: Severity::Info,
+                    file: site.file.clone(),
+                    line: site.line,
+                    service: "redis",
+                    message,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Follows a Redis handle across statements: `let client =
+/// redis::Client::open(..)?;` binds `client`, and `let conn =
+/// client.get_connection()?;` (or the async/multiplexed variants)
+/// transitively binds `conn`, so command calls made on either name are
+/// recognized even though neither mentions "redis" in its own text.
+/// Iterates to a fixpoint so multi-hop chains (client -> conn -> conn2)
+/// resolve too.
+fn redis_bound_idents(sites: &[CallSite]) -> HashSet<(PathBuf, String)> {
+    let mut bound: HashSet<(PathBuf, String)> = HashSet::new();
+    loop {
+        let mut changed = false;
+        for site in sites {
+            let Some(name) = &site.let_binding else { continue };
+            let key = (site.file.clone(), name.clone());
+            if bound.contains(&key) {
+                continue;
+            }
+            let is_client_open =
+                site.receiver_repr.is_none() && ends_with_path(&site.callee, "Client::open");
+            let is_connection_handle = site
+                .receiver_repr
+                .as_deref()
+                .is_some_and(|r| bound.contains(&(site.file.clone(), r.trim().to_string())))
+                && matches!(
+                    site.callee.as_str(),
+                    "get_connection"
+                        | "get_async_connection"
+                        | "get_multiplexed_async_connection"
+                        | "get_tokio_connection"
+                );
+            if is_client_open || is_connection_handle {
+                bound.insert(key);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    bound
+}
+
+/// Maps a Redis command method name to the role it plays, and whether that
+/// command name is distinctive enough to flag on its own. Matched
+/// case-insensitively since the `redis` crate exposes both `PUBLISH`-style
+/// `cmd()` calls and lowercase convenience methods like `.publish(...)`.
+fn command_role(callee: &str) -> Option<(&'static str, bool)> {
+    match callee.to_ascii_lowercase().as_str() {
+        "publish" | "psubscribe" => Some(("pub-sub channel", true)),
+        "subscribe" => Some(("pub-sub channel", false)),
+        "xadd" | "xread" => Some(("stream", true)),
+        "set" | "get" | "setex" | "expire" => Some(("cache", false)),
+        "lpush" | "brpop" => Some(("queue", false)),
+        _ => None,
+    }
+}
+
+/// A command call site plays a Redis role when its command name maps to
+/// one and either: the receiver is a handle we traced back to
+/// `redis::Client::open(..)` (or its text otherwise mentions "redis"), or
+/// the command name is distinctive enough on its own (e.g. `psubscribe`)
+/// that flagging without that confirmation is still low-noise. Generic
+/// command names (`get`, `set`, ...) are only flagged when the receiver is
+/// confirmed, since plenty of non-Redis types expose methods with the same
+/// name.
+fn redis_role(site: &CallSite, redis_bound: &HashSet<(PathBuf, String)>) -> Option<&'static str> {
+    let receiver = site.receiver_repr.as_deref()?;
+    let (role, distinctive) = command_role(&site.callee)?;
+    let confirmed = distinctive
+        || redis_bound.contains(&(site.file.clone(), receiver.trim().to_string()))
+        || receiver.to_ascii_lowercase().contains("redis");
+    confirmed.then_some(role)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::collect::collect_str;
+
+    fn scan(src: &str) -> Vec<Finding> {
+        let sites = collect_str(PathBuf::from("probe.rs"), src).unwrap();
+        RedisRoleDetector.scan(&sites)
+    }
+
+    #[test]
+    fn follows_client_handle_through_get_connection() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let client = redis::Client::open("redis://127.0.0.1/")?;
+                let mut conn = client.get_connection()?;
+                conn.publish("events.fanout", payload)?;
+                conn.set("cache:key", value)?;
+            }
+            "#,
+        );
+        assert!(
+            findings.iter().any(|f| f.message.contains("pub-sub channel")),
+            "expected a pub-sub finding for conn.publish(..), got {findings:?}"
+        );
+        assert!(
+            findings.iter().any(|f| f.message.contains("cache")),
+            "expected a cache finding for conn.set(..), got {findings:?}"
+        );
+    }
+
+    #[test]
+    fn follows_client_handle_through_unwrap_wrapped_connection() {
+        let findings = scan(
+            r#"
+            fn f() {
+                let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+                let mut conn = client.get_connection().unwrap();
+                conn.set("cache:key", value)?;
+            }
+            "#,
+        );
+        assert!(
+            findings.iter().any(|f| f.message.contains("cache")),
+            "expected a cache finding through an .unwrap()-wrapped connection handle, got {findings:?}"
+        );
+    }
+
+    #[test]
+    fn recognizes_receiver_named_after_redis() {
+        let findings = scan(
+            r#"
+            fn f() {
+                redis_conn.xadd("events", "*", fields)?;
+            }
+            "#,
+        );
+        assert!(findings.iter().any(|f| f.message.contains("stream")));
+    }
+
+    #[test]
+    fn does_not_flag_generic_get_set_on_an_unconfirmed_receiver() {
+        let findings = scan(
+            r#"
+            fn f() {
+                cache.set("k", "v");
+                cache.get("k");
+            }
+            "#,
+        );
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use super::Detector;
+use crate::types::{CallSite, Finding, Severity};
+
+/// Reports whether each detected LLM/HTTP endpoint call is consumed as a
+/// one-shot response or held open as a stream, since the two have very
+/// different operational behavior (long-lived connections, backpressure,
+/// partial failures).
+///
+/// Streaming markers are only matched within the endpoint call's own
+/// statement, so e.g. a `.json(...)` body with a literal `"stream": true`
+/// set up in an earlier statement than the `.send()` it belongs to is
+/// missed; this mirrors the per-statement chain grouping the other
+/// detectors use.
+pub struct StreamingDetector;
+
+impl Detector for StreamingDetector {
+    fn name(&self) -> &'static str {
+        "streaming"
+    }
+
+    fn scan(&self, sites: &[CallSite]) -> Vec<Finding> {
+        let streaming_chains = streaming_chains(sites);
+        let openai_bound = openai_bound_idents(sites);
+
+        sites
+            .iter()
+            .filter_map(|site| {
+                let service = llm_endpoint(site, &openai_bound)?;
+                let group = (site.file.clone(), site.stmt_index);
+                let mode = if site.callee == "create_stream" || streaming_chains.contains(&group) {
+                    "streaming (SSE)"
+                } else {
+                    "unary"
+                };
+                Some(Finding {
+                    detector: self.name(),
+                    severity: Severity::Info,
+                    file: site.file.clone(),
+                    line: site.line,
+                    service,
+                    message: format!("{service} call is {mode}"),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Variables bound via `let x = async_openai::Client::new();` (callee
+/// already resolved through any `use` aliasing by the collector), so a
+/// later invocation made through that handle is recognized even though the
+/// call itself never mentions `async_openai`.
+fn openai_bound_idents(sites: &[CallSite]) -> HashSet<(PathBuf, String)> {
+    sites
+        .iter()
+        .filter(|s| s.receiver_repr.is_none() && s.callee == "async_openai::Client::new")
+        .filter_map(|s| Some((s.file.clone(), s.let_binding.clone()?)))
+        .collect()
+}
+
+/// A call site that hits a known LLM endpoint: a raw reqwest POST to the
+/// OpenAI API, or a `.create`/`.create_stream` invocation made through a
+/// variable bound to `async_openai::Client::new()`. The bare constructor
+/// call itself isn't reported here -- streaming vs. unary is a property of
+/// the request that's actually sent, not of building the client.
+fn llm_endpoint(site: &CallSite, openai_bound: &HashSet<(PathBuf, String)>) -> Option<&'static str> {
+    if site.is_method("post") && site.arg_lit(0).is_some_and(|url| url.contains("openai.com")) {
+        return Some("openai");
+    }
+    let receiver = site.receiver_repr.as_deref()?;
+    let root = receiver.split_whitespace().next()?;
+    let is_openai_invocation = site.callee.contains("create")
+        && openai_bound.contains(&(site.file.clone(), root.to_string()));
+    is_openai_invocation.then_some("openai")
+}
+
+/// The `(file, stmt_index)` groups whose call chain shows a streaming
+/// marker: a `.create_stream(...)` call, an `Accept: text/event-stream`
+/// header, or a JSON body with a literal `"stream": true`.
+fn streaming_chains(sites: &[CallSite]) -> HashSet<(std::path::PathBuf, usize)> {
+    sites
+        .iter()
+        .filter(|site| {
+            site.callee == "create_stream"
+                || (site.is_method("header")
+                    && site.arg_lit(0) == Some("Accept")
+                    && site.arg_lit(1) == Some("text/event-stream"))
+                || (site.is_method("json") && is_streaming_body(site))
+        })
+        .map(|site| (site.file.clone(), site.stmt_index))
+        .collect()
+}
+
+/// True when a `.json(...)` call's argument text contains a literal
+/// `"stream": true`, as in `json!({ "stream": true, ... })`.
+fn is_streaming_body(site: &CallSite) -> bool {
+    site.arg_repr(0)
+        .is_some_and(|body| body.contains("stream") && body.contains("true"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::collect::collect_str;
+
+    fn scan(src: &str) -> Vec<Finding> {
+        let sites = collect_str(PathBuf::from("probe.rs"), src).unwrap();
+        StreamingDetector.scan(&sites)
+    }
+
+    #[test]
+    fn classifies_streaming_call_through_aliased_client_handle() {
+        let findings = scan(
+            r#"
+            use async_openai::Client;
+            async fn f() {
+                let openai = Client::new();
+                let stream = openai.create_stream(request).await?;
+            }
+            "#,
+        );
+        assert!(
+            findings
+                .iter()
+                .any(|f| f.service == "openai" && f.message.contains("streaming")),
+            "expected a streaming finding for openai.create_stream(..), got {findings:?}"
+        );
+        assert!(
+            !findings.iter().any(|f| f.message.contains("is streaming") && f.line == 4),
+            "the bare Client::new() line shouldn't itself be classified"
+        );
+    }
+
+    #[test]
+    fn classifies_unary_call_through_client_handle() {
+        let findings = scan(
+            r#"
+            async fn f() {
+                let openai = async_openai::Client::new();
+                let resp = openai.create(request).await?;
+            }
+            "#,
+        );
+        assert!(findings
+            .iter()
+            .any(|f| f.service == "openai" && f.message.contains("is unary")));
+    }
+
+    #[test]
+    fn classifies_raw_reqwest_streaming_body() {
+        let findings = scan(
+            r#"
+            async fn f() {
+                client
+                    .post("https://api.openai.com/v1/completions")
+                    .json(&json!({ "stream": true }))
+                    .send()
+                    .await?;
+            }
+            "#,
+        );
+        assert!(findings
+            .iter()
+            .any(|f| f.service == "openai" && f.message.contains("streaming")));
+    }
+}
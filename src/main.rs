@@ -0,0 +1,55 @@
+mod collect;
+mod detectors;
+mod types;
+
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use walkdir::WalkDir;
+
+use types::{CallSite, Finding};
+
+fn main() -> Result<()> {
+    let root = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    let sites = collect_sites(&root)?;
+
+    let mut findings: Vec<Finding> = detectors::all()
+        .iter()
+        .flat_map(|d| d.scan(&sites))
+        .collect();
+    // Critical findings first, as Severity's own ordering promises.
+    findings.sort_by(|a, b| (Reverse(a.severity), &a.file, a.line).cmp(&(Reverse(b.severity), &b.file, b.line)));
+
+    for finding in &findings {
+        println!(
+            "[{}] {}:{} {}/{}: {}",
+            finding.severity,
+            finding.file.display(),
+            finding.line,
+            finding.detector,
+            finding.service,
+            finding.message
+        );
+    }
+
+    Ok(())
+}
+
+/// Parses every `.rs` file under `root` and collects their call sites.
+fn collect_sites(root: &Path) -> Result<Vec<CallSite>> {
+    let mut sites = Vec::new();
+    for entry in WalkDir::new(root)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "rs"))
+    {
+        sites.extend(collect::collect_file(entry.path())?);
+    }
+    Ok(sites)
+}
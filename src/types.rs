@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+/// How urgent a [`Finding`] is. Ordered so that higher-severity findings sort
+/// to the top of a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Severity::Info => "info",
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        };
+        f.write_str(s)
+    }
+}
+
+/// One call expression (either a free/associated function call like
+/// `PgPool::connect(...)` or a method call like `client.post(...)`) collected
+/// while walking a source file. Detectors work off of these rather than
+/// re-walking the AST themselves.
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    pub file: PathBuf,
+    pub line: usize,
+    /// Index of the top-level statement this call site was found in, so
+    /// detectors can group call sites belonging to the same builder chain
+    /// (e.g. `client.post(..).header(..).send()` all share one index).
+    pub stmt_index: usize,
+    /// Full dotted path for a plain call (`redis::Client::open`), or just the
+    /// method name for a method call (`open`, `header`, `post`, ...).
+    pub callee: String,
+    /// Source text of the receiver expression for a method call, e.g.
+    /// `"client"` or `"reqwest::Client::new()"`. `None` for plain calls.
+    pub receiver_repr: Option<String>,
+    /// Source text of each argument expression, rendered via `quote!`, e.g.
+    /// `"Method::POST"` or `"\"Authorization\""`.
+    pub arg_reprs: Vec<String>,
+    /// The argument's literal string value, when that argument is a plain
+    /// string literal (`"..."`), indexed by argument position.
+    pub arg_lits: Vec<Option<String>>,
+    /// Name of the function this call site appears in, if any.
+    pub enclosing_fn: Option<String>,
+    /// Whether the enclosing function carries a `#[tracing::instrument]` (or
+    /// `#[instrument]`) attribute.
+    pub in_traced_fn: bool,
+    /// Name this call site's result is directly bound to via
+    /// `let <name> = <call>;`, if any. Lets detectors follow a handle (e.g.
+    /// a Redis connection, an SDK client) across statements instead of only
+    /// recognizing it at its construction site.
+    pub let_binding: Option<String>,
+}
+
+impl CallSite {
+    pub fn is_method(&self, method: &str) -> bool {
+        self.receiver_repr.is_some() && self.callee == method
+    }
+
+    pub fn arg_lit(&self, index: usize) -> Option<&str> {
+        self.arg_lits.get(index).and_then(|a| a.as_deref())
+    }
+
+    pub fn arg_repr(&self, index: usize) -> Option<&str> {
+        self.arg_reprs.get(index).map(String::as_str)
+    }
+}
+
+/// A single piece of evidence surfaced by a detector.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub detector: &'static str,
+    pub severity: Severity,
+    pub file: PathBuf,
+    pub line: usize,
+    /// The service/integration this finding is about, e.g. "postgres" or
+    /// "redis".
+    pub service: &'static str,
+    pub message: String,
+}